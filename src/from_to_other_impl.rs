@@ -6,18 +6,21 @@ use syn::punctuated::Punctuated;
 
 pub(crate) struct KeyValuePair {
     pub path: Path,
-    pub eq_token: Token![=],
-    pub token_tree: TokenTree,
+    pub value: Option<(Token![=], TokenTree)>,
 }
 impl Parse for KeyValuePair {
     fn parse(input: ParseStream) -> ParseResult<Self> {
         let path = input.parse()?;
-        let eq_token = input.parse()?;
-        let token_tree = input.parse()?;
+        let value = if input.peek(Token![=]) {
+            let eq_token = input.parse()?;
+            let token_tree = input.parse()?;
+            Some((eq_token, token_tree))
+        } else {
+            None
+        };
         Ok(Self {
             path,
-            eq_token,
-            token_tree,
+            value,
         })
     }
 }
@@ -4,11 +4,400 @@ mod from_to_other_impl;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Meta, NestedMeta, parse_macro_input};
+use syn::{Attribute, Data, DeriveInput, Expr, Ident, Meta, NestedMeta, Token, UnOp, parse_macro_input};
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+
+
+/// Returns `true` if the given discriminant expression is a literal (or a negated literal) and
+/// therefore usable as a `match` pattern, allowing the compiler to lower the conversion into a
+/// jump table instead of a comparison chain.
+fn is_match_pattern_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(lit) => matches!(lit.lit, syn::Lit::Int(_)),
+        Expr::Unary(unary) => matches!(unary.op, UnOp::Neg(_)) && is_match_pattern_literal(&unary.expr),
+        _ => false,
+    }
+}
+
+
+/// A numeric literal value represented as a sign flag plus a `u128` magnitude, so it can hold any
+/// value of any supported repr width without loss, including `u128::MAX` (which does not fit in
+/// an `i128`). Magnitude `0` is always stored as non-negative, so `0` and `-0` compare equal.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct LiteralValue {
+    negative: bool,
+    magnitude: u128,
+}
+impl LiteralValue {
+    fn new(negative: bool, magnitude: u128) -> Self {
+        Self { negative: negative && magnitude != 0, magnitude }
+    }
+}
+impl std::fmt::Display for LiteralValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            write!(f, "-{}", self.magnitude)
+        } else {
+            write!(f, "{}", self.magnitude)
+        }
+    }
+}
+
+/// Attempts to evaluate a discriminant/alternative expression as a [`LiteralValue`], for
+/// duplicate-value detection. Only integer literals (optionally negated) are evaluable at
+/// macro-expansion time; anything else (e.g. a named constant) returns `None` and is left
+/// unchecked by the caller.
+fn literal_value(expr: &Expr) -> Option<LiteralValue> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            syn::Lit::Int(int) => int.base10_parse::<u128>().ok().map(|magnitude| LiteralValue::new(false, magnitude)),
+            _ => None,
+        },
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            let inner = literal_value(&unary.expr)?;
+            Some(LiteralValue::new(!inner.negative, inner.magnitude))
+        },
+        _ => None,
+    }
+}
+
+
+/// Collects the values listed in a variant's `#[from_to_repr(alternatives = [..])]` attribute, if
+/// any. These are additional representation values that map onto the same variant as its
+/// canonical discriminant, mirroring num_enum's `#[num_enum(alternatives = [..])]`.
+fn variant_alternatives(attrs: &[Attribute]) -> syn::Result<Vec<Expr>> {
+    let mut alternatives = Vec::new();
+    for attr in attrs {
+        if !attr.path.is_ident("from_to_repr") {
+            continue;
+        }
+        let values = attr.parse_args_with(|input: ParseStream| {
+            let key: Ident = input.parse()?;
+            if key.to_string() != "alternatives" {
+                return Err(syn::Error::new(key.span(), "unknown \"from_to_repr\" attribute argument; expected \"alternatives\""));
+            }
+            input.parse::<Token![=]>()?;
+            let content;
+            syn::bracketed!(content in input);
+            let exprs = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
+            Ok(exprs.into_iter().collect::<Vec<_>>())
+        })?;
+        alternatives.extend(values);
+    }
+    Ok(alternatives)
+}
+
+
+/// A single `key` or `key = value` entry of a container-level `#[from_to_repr(..)]` attribute.
+/// The value (if any) is kept as raw tokens, since different keys (`error`, `rename_all`, ...)
+/// expect different grammars.
+struct ContainerArg {
+    key: Ident,
+    value: Option<proc_macro2::TokenStream>,
+}
+
+/// Parses every container-level `#[from_to_repr(..)]` attribute into its `key`/`key = value`
+/// entries. Several derives applied to the same enum may share one such attribute, each picking
+/// out the keys relevant to it, so unrecognized keys are left for the caller to deal with.
+fn parse_container_args(attrs: &[Attribute]) -> syn::Result<Vec<ContainerArg>> {
+    let mut args = Vec::new();
+    for attr in attrs {
+        if !attr.path.is_ident("from_to_repr") {
+            continue;
+        }
+        let parsed = attr.parse_args_with(|input: ParseStream| {
+            let mut args = Vec::new();
+            while !input.is_empty() {
+                let key: Ident = input.parse()?;
+                let value = if input.peek(Token![=]) {
+                    input.parse::<Token![=]>()?;
+                    let mut value_tokens = proc_macro2::TokenStream::new();
+                    while !input.is_empty() && !input.peek(Token![,]) {
+                        let tt: proc_macro2::TokenTree = input.parse()?;
+                        value_tokens.extend(std::iter::once(tt));
+                    }
+                    Some(value_tokens)
+                } else {
+                    None
+                };
+                args.push(ContainerArg { key, value });
+                if input.peek(Token![,]) {
+                    input.parse::<Token![,]>()?;
+                } else {
+                    break;
+                }
+            }
+            Ok(args)
+        })?;
+        args.extend(parsed);
+    }
+    Ok(args)
+}
+
+/// Reads the `rename_all` style from a container-level `#[from_to_repr(rename_all = "..")]`
+/// attribute, if present.
+fn container_rename_all(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+    for arg in parse_container_args(attrs)? {
+        if arg.key.to_string() != "rename_all" {
+            continue;
+        }
+        let value_tokens = match arg.value {
+            Some(vt) => vt,
+            None => return Err(syn::Error::new(arg.key.span(), "\"rename_all\" requires a value")),
+        };
+        let key_span = arg.key.span();
+        let style: syn::LitStr = syn::parse2(value_tokens)
+            .map_err(|_| syn::Error::new(key_span, "\"rename_all\" value must be a string literal"))?;
+        let style_value = style.value();
+        if rename_all_style(&style_value).is_none() {
+            return Err(syn::Error::new(style.span(), "\"rename_all\" value must be one of: \"lowercase\", \"UPPERCASE\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"kebab-case\", \"camelCase\", \"PascalCase\""));
+        }
+        return Ok(Some(style_value));
+    }
+    Ok(None)
+}
+
+
+/// The error-handling strategy configured for `FromToRepr`'s generated `TryFrom` impl, via the
+/// `#[from_to_repr(error = ..)]` / `#[from_to_repr(error_struct)]` container attributes.
+enum ErrorConfig {
+    /// `type Error = #inner_type;` (the default, unchanged for backward compatibility).
+    Bare,
+    /// `type Error = #path;`, constructed via `#path::from(value)`.
+    Custom(syn::Path),
+    /// A dedicated zero-boilerplate error struct is generated alongside the `TryFrom` impl.
+    GeneratedStruct,
+}
+
+/// Reads the `error`/`error_struct` container-level attributes that configure `FromToRepr`'s
+/// generated `TryFrom::Error` type.
+fn container_error_config(attrs: &[Attribute]) -> syn::Result<ErrorConfig> {
+    let mut error_path_opt = None;
+    let mut error_struct = false;
+    for arg in parse_container_args(attrs)? {
+        if arg.key.to_string() == "error" {
+            if error_path_opt.is_some() {
+                return Err(syn::Error::new(arg.key.span(), "cannot set \"error\" more than once"));
+            }
+            let value_tokens = match arg.value {
+                Some(vt) => vt,
+                None => return Err(syn::Error::new(arg.key.span(), "\"error\" requires a value")),
+            };
+            let key_span = arg.key.span();
+            let path: syn::Path = syn::parse2(value_tokens)
+                .map_err(|_| syn::Error::new(key_span, "\"error\" value must be a path to a type"))?;
+            error_path_opt = Some(path);
+        } else if arg.key.to_string() == "error_struct" {
+            if let Some(value) = arg.value {
+                return Err(syn::Error::new(value.span(), "\"error_struct\" is a flag and does not take a value"));
+            }
+            error_struct = true;
+        }
+    }
+    match (error_path_opt, error_struct) {
+        (Some(path), true) => Err(syn::Error::new(path.span(), "\"error\" and \"error_struct\" are mutually exclusive")),
+        (Some(path), false) => Ok(ErrorConfig::Custom(path)),
+        (None, true) => Ok(ErrorConfig::GeneratedStruct),
+        (None, false) => Ok(ErrorConfig::Bare),
+    }
+}
+
+
+/// Reads the `const_fn` container-level flag that opts `FromToRepr` into additionally generating
+/// inherent `const fn from_repr`/`const fn to_repr` methods.
+fn container_const_fn(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut const_fn = false;
+    for arg in parse_container_args(attrs)? {
+        if arg.key.to_string() != "const_fn" {
+            continue;
+        }
+        if let Some(value) = arg.value {
+            return Err(syn::Error::new(value.span(), "\"const_fn\" is a flag and does not take a value"));
+        }
+        const_fn = true;
+    }
+    Ok(const_fn)
+}
+
+
+/// Returns `Some(())` if `style` names a supported `rename_all` case convention.
+fn rename_all_style(style: &str) -> Option<()> {
+    match style {
+        "lowercase" | "UPPERCASE" | "snake_case" | "SCREAMING_SNAKE_CASE" | "kebab-case" | "camelCase" | "PascalCase" => Some(()),
+        _ => None,
+    }
+}
+
+
+/// Splits an identifier like `SetRed` into its constituent words (`["Set", "Red"]`), treating
+/// underscores and case transitions as word boundaries.
+fn split_ident_words(ident: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut chars = ident.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '_' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !current.is_empty() {
+            let prev_is_lower = current.chars().last().unwrap().is_lowercase();
+            let next_is_lower = chars.peek().map(|nc| nc.is_lowercase()).unwrap_or(false);
+            if prev_is_lower || next_is_lower {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+
+/// Capitalizes the first character of `word` and lowercases the rest.
+fn capitalize_word(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+    }
+}
+
+
+/// Transforms a variant identifier according to a `rename_all` case convention.
+fn rename_variant(ident: &str, style: &str) -> String {
+    let words = split_ident_words(ident);
+    match style {
+        "lowercase" => ident.to_lowercase(),
+        "UPPERCASE" => ident.to_uppercase(),
+        "snake_case" => words.iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("_"),
+        "SCREAMING_SNAKE_CASE" => words.iter().map(|w| w.to_uppercase()).collect::<Vec<_>>().join("_"),
+        "kebab-case" => words.iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("-"),
+        "camelCase" => words.iter().enumerate()
+            .map(|(i, w)| if i == 0 { w.to_lowercase() } else { capitalize_word(w) })
+            .collect::<Vec<_>>()
+            .concat(),
+        "PascalCase" => words.iter().map(|w| capitalize_word(w)).collect::<Vec<_>>().concat(),
+        _ => ident.to_string(),
+    }
+}
 
 
 /// Derives [`TryFrom`] and [`From`] implementations for the representation type of an enumeration.
 ///
+/// If every discriminant and every alternative value (see below) is an integer literal (or the
+/// negation of one), the generated `try_from` is a `match` over these values, which the compiler
+/// can usually lower into a jump table. If any of them is a non-literal const expression, it
+/// falls back to a chain of comparisons, since such expressions are not legal match patterns.
+///
+/// A variant can absorb additional representation values via
+/// `#[from_to_repr(alternatives = [..])]`; the variant's own discriminant remains the canonical
+/// value produced by `From<Self> for #inner_type`, but `TryFrom<#inner_type>` also maps every
+/// listed alternative onto that variant. If a value (discriminant or alternative) is claimed by
+/// more than one variant, this is a compile error rather than being resolved by declaration order
+/// (values that aren't integer literals, e.g. named constants, cannot be checked this way).
+///
+/// ```
+/// use from_to_repr::FromToRepr;
+///
+/// #[derive(FromToRepr, Debug, PartialEq)]
+/// #[repr(u8)]
+/// enum ColorChannelAlt {
+///     Red = 0,
+///     #[from_to_repr(alternatives = [3, 4])]
+///     Green = 1,
+///     Blue = 2,
+/// }
+///
+/// assert_eq!(ColorChannelAlt::try_from(1), Ok(ColorChannelAlt::Green));
+/// assert_eq!(ColorChannelAlt::try_from(3), Ok(ColorChannelAlt::Green));
+/// assert_eq!(ColorChannelAlt::try_from(4), Ok(ColorChannelAlt::Green));
+/// assert_eq!(u8::from(ColorChannelAlt::Green), 1);
+/// ```
+///
+/// By default, the generated `TryFrom::Error` is the representation type itself (the invalid
+/// value), which is unchanged for backward compatibility. Two container-level attributes allow
+/// configuring this:
+///
+/// * `#[from_to_repr(error = path::to::MyError)]` uses `MyError` as the `Error` type, constructed
+///   via `MyError::from(value)`.
+///
+/// ```
+/// use from_to_repr::FromToRepr;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct BadColorChannel(u8);
+/// impl From<u8> for BadColorChannel {
+///     fn from(value: u8) -> Self {
+///         Self(value)
+///     }
+/// }
+///
+/// #[derive(FromToRepr, Debug, PartialEq)]
+/// #[repr(u8)]
+/// #[from_to_repr(error = BadColorChannel)]
+/// enum ColorChannelCustomError {
+///     Red = 0,
+///     Green = 1,
+///     Blue = 2,
+/// }
+///
+/// assert_eq!(ColorChannelCustomError::try_from(42), Err(BadColorChannel(42)));
+/// ```
+///
+/// * `#[from_to_repr(error_struct)]` generates a dedicated zero-boilerplate error struct (named
+///   `<EnumName>TryFromError`, holding the invalid representation value) that implements
+///   [`core::fmt::Display`] and [`core::error::Error`].
+///
+/// ```
+/// use from_to_repr::FromToRepr;
+///
+/// #[derive(FromToRepr, Debug, PartialEq)]
+/// #[repr(u8)]
+/// #[from_to_repr(error_struct)]
+/// enum ColorChannelErrorStruct {
+///     Red = 0,
+///     Green = 1,
+///     Blue = 2,
+/// }
+///
+/// let err = ColorChannelErrorStruct::try_from(42).unwrap_err();
+/// assert_eq!(err.0, 42);
+/// assert_eq!(err.to_string(), "42 is not a valid value for ColorChannelErrorStruct");
+/// ```
+///
+/// These two are mutually exclusive.
+///
+/// `#[from_to_repr(const_fn)]` additionally generates inherent `const fn from_repr(value:
+/// #inner_type) -> Option<Self>` and `const fn to_repr(self) -> #inner_type` methods, mirroring
+/// the `TryFrom`/`From` impls but usable in `const` context (trait methods cannot be called
+/// there).
+///
+/// ```
+/// use from_to_repr::FromToRepr;
+///
+/// #[derive(FromToRepr, Debug, PartialEq)]
+/// #[repr(u8)]
+/// #[from_to_repr(const_fn)]
+/// enum ColorChannelConst {
+///     Red = 0,
+///     Green = 1,
+///     Blue = 2,
+/// }
+///
+/// const GREEN: Option<ColorChannelConst> = ColorChannelConst::from_repr(1);
+/// assert_eq!(GREEN, Some(ColorChannelConst::Green));
+///
+/// const GREEN_REPR: u8 = ColorChannelConst::Green.to_repr();
+/// assert_eq!(GREEN_REPR, 1);
+/// ```
+///
 /// ```
 /// use from_to_repr::FromToRepr;
 ///
@@ -31,14 +420,11 @@ use syn::{Data, DeriveInput, Meta, NestedMeta, parse_macro_input};
 /// impl ::core::convert::TryFrom<u8> for ColorChannel {
 ///     type Error = u8;
 ///     fn try_from(value: u8) -> Result<Self, Self::Error> {
-///         if value == 0 {
-///             Ok(Self::RED)
-///         } else if value == 1 {
-///             Ok(Self::GREEN)
-///         } else if value == 2 {
-///             Ok(Self::BLUE)
-///         } else {
-///             Err(value)
+///         match value {
+///             0 => Ok(Self::RED),
+///             1 => Ok(Self::GREEN),
+///             2 => Ok(Self::BLUE),
+///             _ => Err(value),
 ///         }
 ///     }
 /// }
@@ -52,25 +438,31 @@ use syn::{Data, DeriveInput, Meta, NestedMeta, parse_macro_input};
 ///     }
 /// }
 /// ```
-#[proc_macro_derive(FromToRepr)]
+#[proc_macro_derive(FromToRepr, attributes(from_to_repr))]
 pub fn derive_from_to_repr(item: TokenStream) -> TokenStream {
     let ast: DeriveInput = parse_macro_input!(item);
+    match derive_from_to_repr_impl(ast) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+fn derive_from_to_repr_impl(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let enum_name = ast.ident;
 
     let enum_data = match ast.data {
         Data::Enum(ed) => ed,
-        _ => panic!("#[derive(FromToPrimitive)] can only be applied to enums"),
+        _ => return Err(syn::Error::new(enum_name.span(), "#[derive(FromToRepr)] can only be applied to enums")),
     };
 
     let enum_repr = ast.attrs.iter()
         .filter(|attr| attr.path.is_ident("repr"))
         .nth(0)
-        .expect("#[derive(FromToPrimitive)] can only be applied to enums with a #[repr(...)] attribute");
-    let enum_repr_type = enum_repr.parse_meta()
-        .expect("#[derive(FromToPrimitive)] failed to parse #[repr(...)] attribute");
+        .ok_or_else(|| syn::Error::new(enum_name.span(), "#[derive(FromToRepr)] can only be applied to enums with a #[repr(...)] attribute"))?;
+    let enum_repr_type = enum_repr.parse_meta()?;
     let enum_list = match enum_repr_type {
         Meta::List(el) => el,
-        _ => panic!("#[derive(FromToPrimitive)] failed to parse #[repr(...)] attribute as a list"),
+        _ => return Err(syn::Error::new(enum_repr.span(), "#[derive(FromToRepr)] failed to parse #[repr(...)] attribute as a list")),
     };
     let reprs = enum_list.nested;
     let mut inner_type_opt = None;
@@ -81,10 +473,10 @@ pub fn derive_from_to_repr(item: TokenStream) -> TokenStream {
                     if ident == "u8" || ident == "u16" || ident == "u32" || ident == "u64" || ident == "u128" || ident == "usize"
                             || ident == "i8" || ident == "i16" || ident == "i32" || ident == "i64" || ident == "i128" || ident == "isize" {
                         if let Some(existing_type) = &inner_type_opt {
-                            panic!(
-                                "#[derive(FromToPrimitive)] found multiple types in #[repr(...)] -- at least {:?} and {:?}",
-                                existing_type, ident,
-                            );
+                            return Err(syn::Error::new(
+                                ident.span(),
+                                format!("#[derive(FromToRepr)] found multiple types in #[repr(...)] -- at least {} and {}", existing_type, ident),
+                            ));
                         } else {
                             inner_type_opt = Some(ident.clone());
                         }
@@ -96,41 +488,173 @@ pub fn derive_from_to_repr(item: TokenStream) -> TokenStream {
 
     let inner_type = match inner_type_opt {
         Some(it) => it,
-        None => panic!("#[derive(FromToPrimitive)] did not find a type in #[repr(...)]"),
+        None => return Err(syn::Error::new(enum_repr.span(), "#[derive(FromToRepr)] did not find a type in #[repr(...)]")),
     };
 
+    let error_config = container_error_config(&ast.attrs)?;
+    let error_struct_name = Ident::new(&format!("{}TryFromError", enum_name), enum_name.span());
+    let emit_const_fns = container_const_fn(&ast.attrs)?;
+
     let mut from_enum_arms: Vec<proc_macro2::TokenStream> = Vec::with_capacity(enum_data.variants.len());
     let mut try_from_inner_ifs: Vec<proc_macro2::TokenStream> = Vec::with_capacity(enum_data.variants.len());
+    let mut try_from_match_arms: Vec<proc_macro2::TokenStream> = Vec::with_capacity(enum_data.variants.len());
+    let mut from_repr_inner_ifs: Vec<proc_macro2::TokenStream> = Vec::with_capacity(enum_data.variants.len());
+    let mut from_repr_match_arms: Vec<proc_macro2::TokenStream> = Vec::with_capacity(enum_data.variants.len());
+    let mut all_discriminants_are_match_patterns = true;
+    let mut seen_values: Vec<(LiteralValue, String)> = Vec::new();
     for variant in enum_data.variants {
         let variant_name = variant.ident;
 
         if variant.fields.len() > 0 {
-            panic!("#[derive(FromToPrimitive)] cannot be used on enums whose variants have fields");
+            return Err(syn::Error::new(variant_name.span(), "#[derive(FromToRepr)] cannot be used on enums whose variants have fields"));
         }
 
         let discriminant = match variant.discriminant {
             Some((_eq_sign, d)) => d,
-            None => panic!("#[derive(FromToPrimitive)] requires that all enum entries have explicit discriminants"),
+            None => return Err(syn::Error::new(variant_name.span(), "#[derive(FromToRepr)] requires that all enum entries have explicit discriminants")),
         };
 
+        let alternatives = variant_alternatives(&variant.attrs)?;
+
+        if !is_match_pattern_literal(&discriminant) || alternatives.iter().any(|a| !is_match_pattern_literal(a)) {
+            all_discriminants_are_match_patterns = false;
+        }
+
+        // a variant's own discriminant and all of its alternatives claim the same representation
+        // value; reject any value (evaluable as a literal) that has already been claimed by an
+        // earlier variant, instead of silently letting declaration order decide the winner
+        for candidate in std::iter::once(&discriminant).chain(alternatives.iter()) {
+            let Some(value) = literal_value(candidate) else { continue };
+            if let Some((_, existing_owner)) = seen_values.iter().find(|(v, _)| *v == value) {
+                return Err(syn::Error::new(
+                    candidate.span(),
+                    format!(
+                        "#[derive(FromToRepr)] value {} is already claimed by variant \"{}\"; each representation value (discriminant or alternative) may only belong to one variant",
+                        value, existing_owner,
+                    ),
+                ));
+            }
+            seen_values.push((value, variant_name.to_string()));
+        }
+
         from_enum_arms.push(quote!{
             #enum_name::#variant_name => #discriminant,
         });
         try_from_inner_ifs.push(quote!{
-            if value == #discriminant {
+            if value == #discriminant #(|| value == #alternatives)* {
                 Ok(Self::#variant_name)
             } else
         });
+        try_from_match_arms.push(quote!{
+            #discriminant #(| #alternatives)* => Ok(Self::#variant_name),
+        });
+        from_repr_inner_ifs.push(quote!{
+            if value == #discriminant #(|| value == #alternatives)* {
+                Some(Self::#variant_name)
+            } else
+        });
+        from_repr_match_arms.push(quote!{
+            #discriminant #(| #alternatives)* => Some(Self::#variant_name),
+        });
     }
 
+    // build the error-construction expression and, if requested, the dedicated error type
+    let error_ctor = match &error_config {
+        ErrorConfig::Bare => quote! { value },
+        ErrorConfig::Custom(path) => quote! { #path::from(value) },
+        ErrorConfig::GeneratedStruct => quote! { #error_struct_name::from(value) },
+    };
+    let error_type = match &error_config {
+        ErrorConfig::Bare => quote! { #inner_type },
+        ErrorConfig::Custom(path) => quote! { #path },
+        ErrorConfig::GeneratedStruct => quote! { #error_struct_name },
+    };
+    let error_struct_def = if let ErrorConfig::GeneratedStruct = error_config {
+        quote! {
+            /// The value did not match any known variant.
+            #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+            pub struct #error_struct_name(pub #inner_type);
+            impl ::core::fmt::Display for #error_struct_name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "{} is not a valid value for {}", self.0, stringify!(#enum_name))
+                }
+            }
+            impl ::core::error::Error for #error_struct_name {}
+            impl ::core::convert::From<#inner_type> for #error_struct_name {
+                fn from(value: #inner_type) -> Self {
+                    Self(value)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // if every discriminant and alternative is a literal (or a negated literal), it can be used as
+    // a match pattern, which lets the compiler lower the conversion into a jump table; otherwise, fall
+    // back to the comparison chain, since non-literal const expressions are not legal patterns
+    let try_from_body = if all_discriminants_are_match_patterns {
+        quote! {
+            match value {
+                #(#try_from_match_arms)*
+                _ => Err(#error_ctor),
+            }
+        }
+    } else {
+        quote! {
+            #(#try_from_inner_ifs)*
+            {
+                Err(#error_ctor)
+            }
+        }
+    };
+
+    // same jump-table-vs-comparison-chain choice as `try_from_body`, but returning `Option` so the
+    // methods can be `const fn` (trait methods like `TryFrom`/`From` cannot be called in const
+    // context)
+    let const_fn_impl = if emit_const_fns {
+        let from_repr_body = if all_discriminants_are_match_patterns {
+            quote! {
+                match value {
+                    #(#from_repr_match_arms)*
+                    _ => None,
+                }
+            }
+        } else {
+            quote! {
+                #(#from_repr_inner_ifs)*
+                {
+                    None
+                }
+            }
+        };
+        quote! {
+            impl #enum_name {
+                /// Attempts to convert a representation value into this enumeration, like
+                /// [`TryFrom`], but usable in `const` context.
+                pub const fn from_repr(value: #inner_type) -> Option<Self> {
+                    #from_repr_body
+                }
+
+                /// Converts this enumeration into its representation value, like [`From`], but
+                /// usable in `const` context.
+                pub const fn to_repr(self) -> #inner_type {
+                    match self {
+                        #(#from_enum_arms)*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let expanded = quote! {
+        #error_struct_def
         impl ::core::convert::TryFrom<#inner_type> for #enum_name {
-            type Error = #inner_type;
+            type Error = #error_type;
             fn try_from(value: #inner_type) -> Result<Self, Self::Error> {
-                #(#try_from_inner_ifs)*
-                {
-                    Err(value)
-                }
+                #try_from_body
             }
         }
         impl ::core::convert::From<#enum_name> for #inner_type {
@@ -140,9 +664,137 @@ pub fn derive_from_to_repr(item: TokenStream) -> TokenStream {
                 }
             }
         }
+        #const_fn_impl
+    };
+
+    Ok(expanded)
+}
+
+
+/// Derives [`core::fmt::Display`] and [`core::str::FromStr`] for an enumeration, converting
+/// between variants and their identifier.
+///
+/// The identifier can be transformed via a container-level
+/// `#[from_to_repr(rename_all = "..")]` attribute; supported values are `"lowercase"`,
+/// `"UPPERCASE"`, `"snake_case"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"`, `"camelCase"` and
+/// `"PascalCase"`. Without it, the variant's own identifier is used verbatim. The rendered names
+/// (after any `rename_all` transformation) must be unique; two variants rendering to the same
+/// name is a compile error.
+///
+/// ```
+/// use from_to_repr::FromToReprStr;
+///
+/// #[derive(FromToReprStr)]
+/// #[from_to_repr(rename_all = "snake_case")]
+/// enum ColorChannel {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+/// ```
+/// is equivalent to
+/// ```
+/// enum ColorChannel {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+/// impl ::core::fmt::Display for ColorChannel {
+///     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+///         let name = match self {
+///             ColorChannel::Red => "red",
+///             ColorChannel::Green => "green",
+///             ColorChannel::Blue => "blue",
+///         };
+///         f.write_str(name)
+///     }
+/// }
+/// impl ::core::str::FromStr for ColorChannel {
+///     type Err = String;
+///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+///         match s {
+///             "red" => Ok(Self::Red),
+///             "green" => Ok(Self::Green),
+///             "blue" => Ok(Self::Blue),
+///             _ => Err(s.to_string()),
+///         }
+///     }
+/// }
+/// ```
+#[proc_macro_derive(FromToReprStr, attributes(from_to_repr))]
+pub fn derive_from_to_repr_str(item: TokenStream) -> TokenStream {
+    let ast: DeriveInput = parse_macro_input!(item);
+    match derive_from_to_repr_str_impl(ast) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+fn derive_from_to_repr_str_impl(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let enum_name = ast.ident;
+
+    let enum_data = match ast.data {
+        Data::Enum(ed) => ed,
+        _ => return Err(syn::Error::new(enum_name.span(), "#[derive(FromToReprStr)] can only be applied to enums")),
+    };
+
+    let rename_all = container_rename_all(&ast.attrs)?;
+
+    let mut display_arms: Vec<proc_macro2::TokenStream> = Vec::with_capacity(enum_data.variants.len());
+    let mut from_str_arms: Vec<proc_macro2::TokenStream> = Vec::with_capacity(enum_data.variants.len());
+    let mut seen_names: Vec<(String, Ident)> = Vec::with_capacity(enum_data.variants.len());
+    for variant in enum_data.variants {
+        let variant_name = variant.ident;
+
+        if variant.fields.len() > 0 {
+            return Err(syn::Error::new(variant_name.span(), "#[derive(FromToReprStr)] cannot be used on enums whose variants have fields"));
+        }
+
+        let rendered_name = match &rename_all {
+            Some(style) => rename_variant(&variant_name.to_string(), style),
+            None => variant_name.to_string(),
+        };
+
+        if let Some((_, existing_owner)) = seen_names.iter().find(|(n, _)| *n == rendered_name) {
+            return Err(syn::Error::new(
+                variant_name.span(),
+                format!(
+                    "#[derive(FromToReprStr)] variant \"{}\" renders to \"{}\", which collides with variant \"{}\"; rendered names must be unique",
+                    variant_name, rendered_name, existing_owner,
+                ),
+            ));
+        }
+        seen_names.push((rendered_name.clone(), variant_name.clone()));
+
+        display_arms.push(quote!{
+            #enum_name::#variant_name => #rendered_name,
+        });
+        from_str_arms.push(quote!{
+            #rendered_name => Ok(Self::#variant_name),
+        });
+    }
+
+    let expanded = quote! {
+        impl ::core::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let name = match self {
+                    #(#display_arms)*
+                };
+                f.write_str(name)
+            }
+        }
+        impl ::core::str::FromStr for #enum_name {
+            type Err = String;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#from_str_arms)*
+                    _ => Err(s.to_string()),
+                }
+            }
+        }
     };
 
-    TokenStream::from(expanded)
+    Ok(expanded)
 }
 
 
@@ -168,6 +820,96 @@ pub fn derive_from_to_repr(item: TokenStream) -> TokenStream {
 ///       equal to the matching known value (e.g. `Self::Other(12) == Self::Twelve` where
 ///       `Twelve = 12`).
 ///
+/// A variant can also absorb additional representation values via
+/// `#[from_to_repr(alternatives = [..])]`; its own discriminant remains the canonical value
+/// produced when converting to the base type, but every listed alternative is also recognized
+/// when converting from the base type. This is rejected on the _Other_ variant. If a value
+/// (discriminant or alternative) is claimed by more than one variant, this is a compile error
+/// rather than being resolved by declaration order (values that aren't integer literals, e.g.
+/// named constants, cannot be checked this way).
+///
+/// ```
+/// use from_to_repr::from_to_other;
+///
+/// #[from_to_other(base_type = u8)]
+/// #[derive(Debug, PartialEq)]
+/// enum ColorCommandAlt {
+///     SetRed = 0,
+///     #[from_to_repr(alternatives = [3, 4])]
+///     SetGreen = 1,
+///     SetBlue = 2,
+///     Other(u8),
+/// }
+///
+/// assert_eq!(ColorCommandAlt::from(3u8), ColorCommandAlt::SetGreen);
+/// assert_eq!(ColorCommandAlt::from(4u8), ColorCommandAlt::SetGreen);
+/// assert_eq!(u8::from(ColorCommandAlt::SetGreen), 1);
+/// ```
+///
+/// * `derive_str` (optional, flag): Additionally derives [`core::fmt::Display`] and
+///   [`core::str::FromStr`] over the variant names. The _Other_ value is displayed/parsed via the
+///   base type's own `Display`/`FromStr`, so a string that does not match a known variant name but
+///   does parse as the base type is routed into the _Other_ value. The rendered names (after any
+///   `rename_all` transformation) must be unique; two variants rendering to the same name is a
+///   compile error.
+///
+/// * `rename_all` (optional): Transforms variant identifiers before they are used by
+///   `derive_str`; supported values are `"lowercase"`, `"UPPERCASE"`, `"snake_case"`,
+///   `"SCREAMING_SNAKE_CASE"`, `"kebab-case"`, `"camelCase"` and `"PascalCase"`.
+///
+/// * `const_fn` (optional, flag): Additionally generates inherent `const fn from_base(base_value:
+///   #base_type) -> Self` and `const fn to_base(self) -> #base_type` methods, mirroring the
+///   `From` impls above but usable in `const` context. Since the _Other_ variant absorbs every
+///   unmatched value, `from_base` is infallible and returns `Self` directly rather than an
+///   `Option`.
+///
+/// ```
+/// use from_to_repr::from_to_other;
+///
+/// #[from_to_other(base_type = u8, const_fn)]
+/// #[derive(Debug, PartialEq)]
+/// enum ColorCommandConst {
+///     SetRed = 0,
+///     SetGreen = 1,
+///     SetBlue = 2,
+///     Other(u8),
+/// }
+///
+/// const GREEN: ColorCommandConst = ColorCommandConst::from_base(1);
+/// assert_eq!(GREEN, ColorCommandConst::SetGreen);
+///
+/// const GREEN_REPR: u8 = ColorCommandConst::SetGreen.to_base();
+/// assert_eq!(GREEN_REPR, 1);
+/// ```
+///
+/// * `accessors` (optional, flag): Additionally generates an `is_<variant>()` predicate for every
+///   known variant, plus `is_<other>()` and `as_<other>() -> Option<#base_type>` for the _Other_
+///   value, so callers can query or extract a variant without re-matching. The accessor names are
+///   derived from the actual variant identifiers (in `snake_case`), including the _Other_ value's,
+///   rather than being hard-coded to `other`. Two variants rendering to the same accessor name is
+///   a compile error.
+///
+/// ```
+/// use from_to_repr::from_to_other;
+///
+/// #[from_to_other(base_type = u8, accessors)]
+/// enum ColorCommandAccessors {
+///     SetRed = 0,
+///     SetGreen = 1,
+///     SetBlue = 2,
+///     Unknown(u8),
+/// }
+///
+/// let cmd = ColorCommandAccessors::from(1u8);
+/// assert!(cmd.is_set_green());
+/// assert!(!cmd.is_unknown());
+/// assert_eq!(cmd.as_unknown(), None);
+///
+/// let other = ColorCommandAccessors::from(42u8);
+/// assert!(other.is_unknown());
+/// assert_eq!(other.as_unknown(), Some(42));
+/// ```
+///
 /// ```
 /// use from_to_repr::from_to_other;
 ///
@@ -216,9 +958,7 @@ pub fn derive_from_to_repr(item: TokenStream) -> TokenStream {
 #[proc_macro_attribute]
 pub fn from_to_other(attr: TokenStream, item: TokenStream) -> TokenStream {
     use proc_macro2::{TokenStream as TokenStream2, TokenTree};
-    use syn::{Error, Expr, Ident, ItemEnum, LitStr, Type, Variant};
-    use syn::punctuated::Punctuated;
-    use syn::spanned::Spanned;
+    use syn::{Error, ItemEnum, LitStr, Type, Variant};
 
     use crate::from_to_other_impl::KeyValuePairs;
 
@@ -236,37 +976,59 @@ pub fn from_to_other(attr: TokenStream, item: TokenStream) -> TokenStream {
     let mut derive_compare_mode_set = false;
 
     let mut base_type_opt = None;
+    let mut derive_str = false;
+    let mut derive_str_set = false;
+    let mut rename_all_opt = None;
+    let mut const_fn = false;
+    let mut const_fn_set = false;
+    let mut accessors = false;
+    let mut accessors_set = false;
     for arg in args.kvps {
         if arg.path.is_ident("base_type") {
             if base_type_opt.is_some() {
                 return Error::new(arg.path.span(), "cannot set \"base_type\" more than once")
                     .to_compile_error()
                     .into();
-            } else if let TokenTree::Ident(ident) = arg.token_tree {
-                let lit_string = ident.to_string();
-                if lit_string == "u8" || lit_string == "u16" || lit_string == "u16" || lit_string == "u32" || lit_string == "u64" || lit_string == "u128" || lit_string == "usize"
-                        || lit_string == "i8" || lit_string == "i16" || lit_string == "i16" || lit_string == "i32" || lit_string == "i64" || lit_string == "i128" || lit_string == "isize" {
-                    base_type_opt = Some(ident)
-                } else {
-                    return Error::new(ident.span(), "\"base_type\" value must be an integral type like u8")
+            }
+            match arg.value {
+                Some((_eq_token, TokenTree::Ident(ident))) => {
+                    let lit_string = ident.to_string();
+                    if lit_string == "u8" || lit_string == "u16" || lit_string == "u16" || lit_string == "u32" || lit_string == "u64" || lit_string == "u128" || lit_string == "usize"
+                            || lit_string == "i8" || lit_string == "i16" || lit_string == "i16" || lit_string == "i32" || lit_string == "i64" || lit_string == "i128" || lit_string == "isize" {
+                        base_type_opt = Some(ident)
+                    } else {
+                        return Error::new(ident.span(), "\"base_type\" value must be an integral type like u8")
+                            .to_compile_error()
+                            .into();
+                    }
+                },
+                Some((_eq_token, token_tree)) => {
+                    return Error::new(token_tree.span(), "\"base_type\" value must be an integral type like u8")
                         .to_compile_error()
                         .into();
-                }
-            } else {
-                return Error::new(arg.token_tree.span(), "\"base_type\" value must be an integral type like u8")
-                    .to_compile_error()
-                    .into();
+                },
+                None => {
+                    return Error::new(arg.path.span(), "\"base_type\" requires a value, e.g. \"base_type = u8\"")
+                        .to_compile_error()
+                        .into();
+                },
             }
         } else if arg.path.is_ident("derive_compare") {
             if derive_compare_mode_set {
                 return Error::new(arg.path.span(), "cannot set \"derive_compare\" more than once")
                     .to_compile_error()
                     .into();
-            } else if let TokenTree::Literal(ident_literal) = arg.token_tree {
-                let ident_stream: TokenStream2 = TokenTree::from(ident_literal.clone()).into();
-                let ident_result: Result<LitStr, _> = syn::parse2(ident_stream);
-                if let Ok(ident) = ident_result {
-                    match ident.value().as_str() {
+            }
+            let lit_str = match &arg.value {
+                Some((_eq_token, TokenTree::Literal(literal))) => {
+                    let literal_stream: TokenStream2 = TokenTree::from(literal.clone()).into();
+                    syn::parse2::<LitStr>(literal_stream).ok()
+                },
+                _ => None,
+            };
+            match lit_str {
+                Some(lit_str) => {
+                    match lit_str.value().as_str() {
                         "none" => {
                             derive_compare_mode = DeriveCompareMode::None;
                             derive_compare_mode_set = true;
@@ -280,23 +1042,84 @@ pub fn from_to_other(attr: TokenStream, item: TokenStream) -> TokenStream {
                             derive_compare_mode_set = true;
                         },
                         _ => {
-                            return Error::new(ident.span(), "\"derive_compare\" value must be one of: \"none\", \"as_enum\", \"as_int\"")
+                            return Error::new(lit_str.span(), "\"derive_compare\" value must be one of: \"none\", \"as_enum\", \"as_int\"")
                                 .to_compile_error()
                                 .into();
                         },
                     }
-                } else {
-                    return Error::new(ident_literal.span(), "\"derive_compare\" value must be a string literal")
+                },
+                None => {
+                    return Error::new(arg.path.span(), "\"derive_compare\" value must be a string literal")
                         .to_compile_error()
                         .into();
-                };
-            } else {
-                return Error::new(arg.token_tree.span(), "\"derive_compare\" value must be a string literal")
+                },
+            }
+        } else if arg.path.is_ident("derive_str") {
+            if derive_str_set {
+                return Error::new(arg.path.span(), "cannot set \"derive_str\" more than once")
+                    .to_compile_error()
+                    .into();
+            } else if let Some((_eq_token, token_tree)) = arg.value {
+                return Error::new(token_tree.span(), "\"derive_str\" is a flag and does not take a value")
+                    .to_compile_error()
+                    .into();
+            }
+            derive_str = true;
+            derive_str_set = true;
+        } else if arg.path.is_ident("const_fn") {
+            if const_fn_set {
+                return Error::new(arg.path.span(), "cannot set \"const_fn\" more than once")
+                    .to_compile_error()
+                    .into();
+            } else if let Some((_eq_token, token_tree)) = arg.value {
+                return Error::new(token_tree.span(), "\"const_fn\" is a flag and does not take a value")
                     .to_compile_error()
                     .into();
             }
+            const_fn = true;
+            const_fn_set = true;
+        } else if arg.path.is_ident("accessors") {
+            if accessors_set {
+                return Error::new(arg.path.span(), "cannot set \"accessors\" more than once")
+                    .to_compile_error()
+                    .into();
+            } else if let Some((_eq_token, token_tree)) = arg.value {
+                return Error::new(token_tree.span(), "\"accessors\" is a flag and does not take a value")
+                    .to_compile_error()
+                    .into();
+            }
+            accessors = true;
+            accessors_set = true;
+        } else if arg.path.is_ident("rename_all") {
+            if rename_all_opt.is_some() {
+                return Error::new(arg.path.span(), "cannot set \"rename_all\" more than once")
+                    .to_compile_error()
+                    .into();
+            }
+            let lit_str = match &arg.value {
+                Some((_eq_token, TokenTree::Literal(literal))) => {
+                    let literal_stream: TokenStream2 = TokenTree::from(literal.clone()).into();
+                    syn::parse2::<LitStr>(literal_stream).ok()
+                },
+                _ => None,
+            };
+            match lit_str {
+                Some(lit_str) => {
+                    if rename_all_style(&lit_str.value()).is_none() {
+                        return Error::new(lit_str.span(), "\"rename_all\" value must be one of: \"lowercase\", \"UPPERCASE\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"kebab-case\", \"camelCase\", \"PascalCase\"")
+                            .to_compile_error()
+                            .into();
+                    }
+                    rename_all_opt = Some(lit_str.value());
+                },
+                None => {
+                    return Error::new(arg.path.span(), "\"rename_all\" value must be a string literal")
+                        .to_compile_error()
+                        .into();
+                },
+            }
         } else {
-            return Error::new(arg.eq_token.span, "unknown argument")
+            return Error::new(arg.path.span(), "unknown argument")
                 .to_compile_error()
                 .into();
         }
@@ -310,10 +1133,16 @@ pub fn from_to_other(attr: TokenStream, item: TokenStream) -> TokenStream {
     };
 
     // process the enum's variants
-    let mut enum_key_to_value: Vec<(Ident, Expr)> = Vec::new();
+    let mut enum_key_to_value: Vec<(Ident, Expr, Vec<Expr>)> = Vec::new();
     let mut cut_variants = Punctuated::new();
     let mut other_value_name_opt = None;
+    let mut seen_values: Vec<(LiteralValue, String)> = Vec::new();
     for variant in enum_def.variants.iter() {
+        let alternatives = match variant_alternatives(&variant.attrs) {
+            Ok(a) => a,
+            Err(err) => return err.to_compile_error().into(),
+        };
+
         if let Some((_, discr)) = &variant.discriminant {
             if !variant.fields.is_empty() {
                 return Error::new(variant.span(), "enum variant must have either a field or a discriminant, not both")
@@ -321,8 +1150,28 @@ pub fn from_to_other(attr: TokenStream, item: TokenStream) -> TokenStream {
                     .into();
             }
 
-            // remember the discriminant for later; we will be removing it now
-            enum_key_to_value.push((variant.ident.clone(), discr.clone()));
+            // a variant's own discriminant and all of its alternatives claim the same
+            // representation value; reject any value (evaluable as a literal) that has already
+            // been claimed by an earlier variant, instead of silently letting declaration order
+            // decide the winner
+            for candidate in std::iter::once(discr).chain(alternatives.iter()) {
+                let Some(value) = literal_value(candidate) else { continue };
+                if let Some((_, existing_owner)) = seen_values.iter().find(|(v, _)| *v == value) {
+                    return Error::new(
+                        candidate.span(),
+                        format!(
+                            "value {} is already claimed by variant \"{}\"; each representation value (discriminant or alternative) may only belong to one variant",
+                            value, existing_owner,
+                        ),
+                    )
+                        .to_compile_error()
+                        .into();
+                }
+                seen_values.push((value, variant.ident.to_string()));
+            }
+
+            // remember the discriminant (and any alternatives) for later; we will be removing it now
+            enum_key_to_value.push((variant.ident.clone(), discr.clone(), alternatives));
         } else {
             if other_value_name_opt.is_some() {
                 return Error::new(variant.span(), "only one value (the \"other\" value) may contain a field instead of a discriminant")
@@ -334,6 +1183,11 @@ pub fn from_to_other(attr: TokenStream, item: TokenStream) -> TokenStream {
                     .to_compile_error()
                     .into();
             }
+            if !alternatives.is_empty() {
+                return Error::new(variant.span(), "\"alternatives\" cannot be specified on the \"other\" value")
+                    .to_compile_error()
+                    .into();
+            }
             let one_field = variant.fields.iter().nth(0).unwrap();
             if one_field.ident.is_some() {
                 return Error::new(one_field.span(), "the \"other\" value's field must be unnamed")
@@ -354,8 +1208,15 @@ pub fn from_to_other(attr: TokenStream, item: TokenStream) -> TokenStream {
             }
             other_value_name_opt = Some(variant.ident.clone());
         }
+
+        // strip our own helper attribute; it is not a real attribute and must not reach the output enum
+        let attrs = variant.attrs.iter()
+            .filter(|attr| !attr.path.is_ident("from_to_repr"))
+            .cloned()
+            .collect();
+
         cut_variants.push(Variant {
-            attrs: variant.attrs.clone(),
+            attrs,
             ident: variant.ident.clone(),
             discriminant: None,
             fields: variant.fields.clone(),
@@ -392,8 +1253,8 @@ pub fn from_to_other(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     } else {
         let pieces: Vec<TokenStream2> = enum_key_to_value.iter().map(
-            |(key, value)| quote! {
-                if base_value == #value {
+            |(key, value, alternatives)| quote! {
+                if base_value == #value #(|| base_value == #alternatives)* {
                     Self::#key
                 } else
             }
@@ -424,8 +1285,8 @@ pub fn from_to_other(attr: TokenStream, item: TokenStream) -> TokenStream {
             }
         }
     } else {
-        let variants = enum_key_to_value.into_iter().map(
-            |(key, value)| quote! {
+        let variants = enum_key_to_value.iter().map(
+            |(key, value, _alternatives)| quote! {
                 #enum_name::#key => #value,
             }
         );
@@ -481,12 +1342,223 @@ pub fn from_to_other(attr: TokenStream, item: TokenStream) -> TokenStream {
         quote! {}
     };
 
+    // implement Display/FromStr over the variant names, routing unrecognized strings that parse
+    // as the base type into the "other" value, so the textual and numeric paths stay consistent
+    if derive_str {
+        let mut seen_names: Vec<(String, &Ident)> = Vec::new();
+        for (key, _value, _alternatives) in &enum_key_to_value {
+            let name = match &rename_all_opt {
+                Some(style) => rename_variant(&key.to_string(), style),
+                None => key.to_string(),
+            };
+            if let Some((_, existing_owner)) = seen_names.iter().find(|(n, _)| *n == name) {
+                return Error::new(
+                    key.span(),
+                    format!(
+                        "variant \"{}\" renders to \"{}\", which collides with variant \"{}\"; rendered names must be unique",
+                        key, name, existing_owner,
+                    ),
+                )
+                    .to_compile_error()
+                    .into();
+            }
+            seen_names.push((name, key));
+        }
+    }
+
+    let derive_str_impl = if derive_str {
+        let display_arms = enum_key_to_value.iter().map(
+            |(key, _value, _alternatives)| {
+                let name = match &rename_all_opt {
+                    Some(style) => rename_variant(&key.to_string(), style),
+                    None => key.to_string(),
+                };
+                quote! {
+                    Self::#key => f.write_str(#name),
+                }
+            }
+        );
+        let from_str_arms = enum_key_to_value.iter().map(
+            |(key, _value, _alternatives)| {
+                let name = match &rename_all_opt {
+                    Some(style) => rename_variant(&key.to_string(), style),
+                    None => key.to_string(),
+                };
+                quote! {
+                    #name => return Ok(Self::#key),
+                }
+            }
+        );
+        quote! {
+            impl ::core::fmt::Display for #enum_name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        #(#display_arms)*
+                        Self::#other_value_name(v) => ::core::fmt::Display::fmt(v, f),
+                    }
+                }
+            }
+            impl ::core::str::FromStr for #enum_name {
+                type Err = String;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        #(#from_str_arms)*
+                        _ => {},
+                    }
+                    if let Ok(v) = s.parse::<#base_type>() {
+                        return Ok(Self::#other_value_name(v));
+                    }
+                    Err(s.to_string())
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // inherent `const fn` equivalents of the `From` impls above, for use in `const`/`static`
+    // initializers and match-guard constants, where trait methods cannot be called
+    let const_fn_impl = if const_fn {
+        let from_base_body = if enum_key_to_value.is_empty() {
+            quote! { Self::#other_value_name(base_value) }
+        } else {
+            let pieces: Vec<TokenStream2> = enum_key_to_value.iter().map(
+                |(key, value, alternatives)| quote! {
+                    if base_value == #value #(|| base_value == #alternatives)* {
+                        Self::#key
+                    } else
+                }
+            )
+                .collect();
+            quote! {
+                #(#pieces)*
+                {
+                    Self::#other_value_name(base_value)
+                }
+            }
+        };
+        let to_base_body = if enum_key_to_value.is_empty() {
+            quote! {
+                match self {
+                    Self::#other_value_name(v) => v,
+                }
+            }
+        } else {
+            let variants = enum_key_to_value.iter().map(
+                |(key, value, _alternatives)| quote! {
+                    Self::#key => #value,
+                }
+            );
+            quote! {
+                match self {
+                    #(#variants)*
+                    Self::#other_value_name(v) => v,
+                }
+            }
+        };
+        quote! {
+            impl #enum_name {
+                /// Converts a representation value into this enumeration, like [`From`], but
+                /// usable in `const` context.
+                pub const fn from_base(base_value: #base_type) -> Self {
+                    #from_base_body
+                }
+
+                /// Converts this enumeration into its representation value, like [`From`], but
+                /// usable in `const` context.
+                pub const fn to_base(self) -> #base_type {
+                    #to_base_body
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // `is_*`/`as_*` accessor helpers, so callers can query/extract a variant without re-matching;
+    // method names are derived from the actual variant identifiers (in snake_case), including the
+    // "other" value's, rather than being hard-coded
+    let accessors_impl = if accessors {
+        // method names are derived from variant identifiers via `rename_variant`, so two variants
+        // whose names collide once rendered to snake_case (e.g. "Foo" and "foo") would otherwise
+        // silently generate two methods with the same name
+        let mut seen_method_names: Vec<(String, &Ident)> = Vec::new();
+        for (key, _value, _alternatives) in &enum_key_to_value {
+            let method_name = format!("is_{}", rename_variant(&key.to_string(), "snake_case"));
+            if let Some((_, existing_owner)) = seen_method_names.iter().find(|(n, _)| *n == method_name) {
+                return Error::new(
+                    key.span(),
+                    format!(
+                        "variant \"{}\" would generate the accessor method \"{}\", which collides with the one generated for variant \"{}\"; accessor method names must be unique",
+                        key, method_name, existing_owner,
+                    ),
+                )
+                    .to_compile_error()
+                    .into();
+            }
+            seen_method_names.push((method_name, key));
+        }
+        let other_method_name = format!("is_{}", rename_variant(&other_value_name.to_string(), "snake_case"));
+        if let Some((_, existing_owner)) = seen_method_names.iter().find(|(n, _)| *n == other_method_name) {
+            return Error::new(
+                other_value_name.span(),
+                format!(
+                    "the \"other\" value \"{}\" would generate the accessor method \"{}\", which collides with the one generated for variant \"{}\"; accessor method names must be unique",
+                    other_value_name, other_method_name, existing_owner,
+                ),
+            )
+                .to_compile_error()
+                .into();
+        }
+
+        let is_arms = enum_key_to_value.iter().map(
+            |(key, _value, _alternatives)| {
+                let method_name = Ident::new(&format!("is_{}", rename_variant(&key.to_string(), "snake_case")), key.span());
+                quote! {
+                    pub const fn #method_name(&self) -> bool {
+                        match self {
+                            Self::#key => true,
+                            _ => false,
+                        }
+                    }
+                }
+            }
+        );
+        let other_snake = rename_variant(&other_value_name.to_string(), "snake_case");
+        let is_other_method = Ident::new(&format!("is_{}", other_snake), other_value_name.span());
+        let as_other_method = Ident::new(&format!("as_{}", other_snake), other_value_name.span());
+        quote! {
+            impl #enum_name {
+                #(#is_arms)*
+
+                pub const fn #is_other_method(&self) -> bool {
+                    match self {
+                        Self::#other_value_name(_) => true,
+                        _ => false,
+                    }
+                }
+
+                pub const fn #as_other_method(&self) -> Option<#base_type> {
+                    match self {
+                        Self::#other_value_name(v) => Some(*v),
+                        _ => None,
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let output = quote! {
         #derive_compare_top
         #cut_enum
         #from_base_type_impl
         #to_base_type_impl
         #derive_compare_impl
+        #derive_str_impl
+        #const_fn_impl
+        #accessors_impl
     };
     TokenStream::from(output)
 }